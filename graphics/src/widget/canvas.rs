@@ -22,9 +22,13 @@ mod fill;
 mod frame;
 mod geometry;
 mod program;
+mod render;
+mod sprite;
 mod stroke;
 mod text;
 
+pub mod shape;
+
 pub use cache::Cache;
 pub use cursor::Cursor;
 pub use event::Event;
@@ -33,6 +37,9 @@ pub use frame::Frame;
 pub use geometry::Geometry;
 pub use path::Path;
 pub use program::Program;
+pub use render::{render, render_to_pixels};
+pub use shape::Shape;
+pub use sprite::Sprite;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use text::Text;
 
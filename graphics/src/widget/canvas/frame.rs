@@ -0,0 +1,442 @@
+use std::borrow::Cow;
+
+use crate::canvas::{path, Fill, Geometry, Path, Stroke, Text};
+use crate::triangle;
+use crate::Primitive;
+
+use iced_native::image;
+use iced_native::{Point, Rectangle, Size, Vector};
+
+use lyon::tessellation;
+
+/// The frame of a [`Canvas`].
+///
+/// [`Canvas`]: crate::canvas::Canvas
+#[allow(missing_debug_implementations)]
+pub struct Frame {
+    size: Size,
+    buffers: lyon::tessellation::VertexBuffers<triangle::Vertex2D, u32>,
+    primitives: Vec<Primitive>,
+    transforms: Transforms,
+    fill_tessellator: tessellation::FillTessellator,
+    stroke_tessellator: tessellation::StrokeTessellator,
+}
+
+#[derive(Debug)]
+struct Transforms {
+    previous: Vec<Transform>,
+    current: Transform,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Transform {
+    raw: lyon::math::Transform,
+    is_identity: bool,
+}
+
+impl Frame {
+    /// Creates a new empty [`Frame`] with the given dimensions.
+    ///
+    /// The default coordinate system of a [`Frame`] has its origin at the
+    /// top-left corner of its bounds.
+    pub fn new(size: Size) -> Frame {
+        Frame {
+            size,
+            buffers: lyon::tessellation::VertexBuffers::new(),
+            primitives: Vec::new(),
+            transforms: Transforms {
+                previous: Vec::new(),
+                current: Transform {
+                    raw: lyon::math::Transform::identity(),
+                    is_identity: true,
+                },
+            },
+            fill_tessellator: tessellation::FillTessellator::new(),
+            stroke_tessellator: tessellation::StrokeTessellator::new(),
+        }
+    }
+
+    /// Returns the width of the [`Frame`].
+    #[inline]
+    pub fn width(&self) -> f32 {
+        self.size.width
+    }
+
+    /// Returns the height of the [`Frame`].
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.size.height
+    }
+
+    /// Returns the dimensions of the [`Frame`].
+    #[inline]
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the coordinate of the center of the [`Frame`].
+    #[inline]
+    pub fn center(&self) -> Point {
+        Point::new(self.size.width / 2.0, self.size.height / 2.0)
+    }
+
+    /// Draws the given [`Path`] on the [`Frame`] by filling it with the
+    /// provided style.
+    pub fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let Fill { color, rule } = fill.into();
+
+        let mut buffers = tessellation::BuffersBuilder::new(
+            &mut self.buffers,
+            FillVertex(color.into_linear()),
+        );
+
+        let options =
+            tessellation::FillOptions::default().with_fill_rule(rule.into());
+
+        if self.transforms.current.is_identity {
+            self.fill_tessellator.tessellate_path(
+                path.raw(),
+                &options,
+                &mut buffers,
+            )
+        } else {
+            let path = path.transformed(&self.transforms.current.raw);
+
+            self.fill_tessellator.tessellate_path(
+                path.raw(),
+                &options,
+                &mut buffers,
+            )
+        }
+        .expect("Tessellate path");
+    }
+
+    /// Draws an axis-aligned rectangle given its top-left corner coordinate and
+    /// its `Size` on the [`Frame`] by filling it with the provided style.
+    pub fn fill_rectangle(
+        &mut self,
+        top_left: Point,
+        size: Size,
+        fill: impl Into<Fill>,
+    ) {
+        let Fill { color, rule } = fill.into();
+
+        let mut buffers = tessellation::BuffersBuilder::new(
+            &mut self.buffers,
+            FillVertex(color.into_linear()),
+        );
+
+        let top_left = self
+            .transforms
+            .current
+            .raw
+            .transform_point(lyon::math::Point::new(top_left.x, top_left.y));
+
+        let size = self
+            .transforms
+            .current
+            .raw
+            .transform_vector(lyon::math::Vector::new(size.width, size.height));
+
+        let _ = self.fill_tessellator.tessellate_rectangle(
+            &lyon::math::Box2D::new(top_left, top_left + size),
+            &tessellation::FillOptions::default().with_fill_rule(rule.into()),
+            &mut buffers,
+        );
+    }
+
+    /// Draws the stroke of the given [`Path`] on the [`Frame`] with the
+    /// provided style.
+    pub fn stroke(&mut self, path: &Path, stroke: impl Into<Stroke>) {
+        let stroke = stroke.into();
+
+        let mut buffers = tessellation::BuffersBuilder::new(
+            &mut self.buffers,
+            StrokeVertex(stroke.color.into_linear()),
+        );
+
+        let mut options = tessellation::StrokeOptions::default();
+        options.line_width = stroke.width;
+        options.start_cap = stroke.line_cap.into();
+        options.end_cap = stroke.line_cap.into();
+        options.line_join = stroke.line_join.into();
+
+        let path = if stroke.line_dash.segments.is_empty() {
+            Cow::Borrowed(path)
+        } else {
+            Cow::Owned(path::dashed(path, stroke.line_dash))
+        };
+
+        if self.transforms.current.is_identity {
+            self.stroke_tessellator.tessellate_path(
+                path.raw(),
+                &options,
+                &mut buffers,
+            )
+        } else {
+            let path = path.transformed(&self.transforms.current.raw);
+
+            self.stroke_tessellator.tessellate_path(
+                path.raw(),
+                &options,
+                &mut buffers,
+            )
+        }
+        .expect("Stroke path");
+    }
+
+    /// Draws the characters of the given [`Text`] on the [`Frame`], laying the
+    /// string out into multiple lines, wrapping it inside its optional
+    /// `max_width` box and honoring its horizontal and vertical alignment.
+    ///
+    /// __Warning:__ Text currently does not support rotation or scaling.
+    pub fn fill_text(&mut self, text: impl Into<Text>) {
+        let text = text.into();
+
+        let lines = text.lines();
+        let line_height = text.resolved_line_height();
+        let bounds = text.bounds(&lines);
+
+        // Anchor the block of lines according to the vertical alignment.
+        let top = match text.vertical_alignment {
+            iced_native::alignment::Vertical::Top => text.position.y,
+            iced_native::alignment::Vertical::Center => {
+                text.position.y - bounds.height / 2.0
+            }
+            iced_native::alignment::Vertical::Bottom => {
+                text.position.y - bounds.height
+            }
+        };
+
+        // Flush the accumulated mesh so that any geometry drawn before this
+        // text composites *under* it, preserving draw order.
+        self.flush_buffers();
+
+        // Each line is anchored within its wrapping box: the primitive carries
+        // both the box (`bounds.x`/`bounds.width`) and the alignment flag, so
+        // the text backend resolves `Center`/`Right` relative to the box rather
+        // than to the bare position. With no `max_width` the box is empty and
+        // alignment falls back to anchoring at `position.x`.
+        let box_width = text.max_width.unwrap_or(0.0);
+
+        for (index, content) in lines.into_iter().enumerate() {
+            let mut position =
+                Point::new(text.position.x, top + index as f32 * line_height);
+
+            if !self.transforms.current.is_identity {
+                let transformed = self.transforms.current.raw.transform_point(
+                    lyon::math::Point::new(position.x, position.y),
+                );
+
+                position = Point::new(transformed.x, transformed.y);
+            }
+
+            // TODO: Use vectorial text instead of primitive
+            self.primitives.push(Primitive::Text {
+                content,
+                bounds: Rectangle {
+                    x: position.x,
+                    y: position.y,
+                    width: box_width,
+                    height: line_height,
+                },
+                color: text.color,
+                size: text.size,
+                font: text.font,
+                horizontal_alignment: text.horizontal_alignment,
+                vertical_alignment: iced_native::alignment::Vertical::Top,
+            });
+        }
+    }
+
+    /// Measures the bounding [`Size`] of the given [`Text`] once it has been
+    /// laid out, so programs can size backgrounds or arrows relative to it.
+    pub fn measure_text(&self, text: &Text) -> Size {
+        text.bounds(&text.lines())
+    }
+
+    /// Draws the raster image behind the given [`image::Handle`] inside the
+    /// provided `bounds` of the [`Frame`].
+    ///
+    /// The image is composited honoring the current [`translate`] and
+    /// [`scale`] of the [`Frame`], so raster sprites can be placed alongside
+    /// vector geometry. The underlying [`Primitive::Image`] is axis-aligned:
+    /// under a [`rotate`], the image is drawn upright, stretched over the
+    /// axis-aligned bounding box of the rotated corners of `bounds`; its
+    /// raster contents are not rotated.
+    ///
+    /// The emitted [`Primitive`] flows through [`into_geometry`] like any other
+    /// drawing, so a [`Cache`] redrawing with a different `handle` produces a
+    /// fresh [`Geometry`] and repaints correctly.
+    ///
+    /// [`translate`]: Self::translate
+    /// [`scale`]: Self::scale
+    /// [`rotate`]: Self::rotate
+    /// [`into_geometry`]: Self::into_geometry
+    /// [`Cache`]: crate::canvas::Cache
+    pub fn draw_image(
+        &mut self,
+        bounds: Rectangle,
+        handle: impl Into<image::Handle>,
+    ) {
+        let bounds = if self.transforms.current.is_identity {
+            bounds
+        } else {
+            self.transform_bounds(bounds)
+        };
+
+        // Flush the accumulated mesh so that geometry drawn before this image
+        // composites *under* it, preserving draw order.
+        self.flush_buffers();
+
+        self.primitives.push(Primitive::Image {
+            handle: handle.into(),
+            bounds,
+        });
+    }
+
+    /// Moves any tessellated geometry accumulated so far into a
+    /// [`Primitive::Mesh2D`], so that subsequently pushed primitives keep their
+    /// draw order relative to it.
+    fn flush_buffers(&mut self) {
+        if self.buffers.indices.is_empty() {
+            return;
+        }
+
+        let buffers = std::mem::replace(
+            &mut self.buffers,
+            lyon::tessellation::VertexBuffers::new(),
+        );
+
+        self.primitives.push(Primitive::Mesh2D {
+            buffers: triangle::Mesh2D {
+                vertices: buffers.vertices,
+                indices: buffers.indices,
+            },
+            size: self.size,
+        });
+    }
+
+    /// Transforms the four corners of the given `bounds` through the current
+    /// transform and returns their axis-aligned bounding box.
+    fn transform_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let raw = &self.transforms.current.raw;
+
+        let corners = [
+            (bounds.x, bounds.y),
+            (bounds.x + bounds.width, bounds.y),
+            (bounds.x + bounds.width, bounds.y + bounds.height),
+            (bounds.x, bounds.y + bounds.height),
+        ];
+
+        let mut min = Point::new(f32::INFINITY, f32::INFINITY);
+        let mut max = Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+        for (x, y) in corners {
+            let point = raw.transform_point(lyon::math::Point::new(x, y));
+
+            min.x = min.x.min(point.x);
+            min.y = min.y.min(point.y);
+            max.x = max.x.max(point.x);
+            max.y = max.y.max(point.y);
+        }
+
+        Rectangle {
+            x: min.x,
+            y: min.y,
+            width: max.x - min.x,
+            height: max.y - min.y,
+        }
+    }
+
+    /// Stores the current transform of the [`Frame`] and executes the given
+    /// drawing operations, restoring the transform afterwards.
+    ///
+    /// This method is useful to compose transforms and perform drawing
+    /// operations in different coordinate systems.
+    #[inline]
+    pub fn with_save(&mut self, f: impl FnOnce(&mut Frame)) {
+        self.transforms.previous.push(self.transforms.current);
+
+        f(self);
+
+        self.transforms.current = self.transforms.previous.pop().unwrap();
+    }
+
+    /// Applies a translation to the current transform of the [`Frame`].
+    #[inline]
+    pub fn translate(&mut self, translation: Vector) {
+        self.transforms.current.raw = self
+            .transforms
+            .current
+            .raw
+            .pre_translate(lyon::math::Vector::new(
+                translation.x,
+                translation.y,
+            ));
+
+        self.transforms.current.is_identity = false;
+    }
+
+    /// Applies a rotation in radians to the current transform of the [`Frame`].
+    #[inline]
+    pub fn rotate(&mut self, angle: f32) {
+        self.transforms.current.raw = self
+            .transforms
+            .current
+            .raw
+            .pre_rotate(lyon::math::Angle::radians(angle));
+
+        self.transforms.current.is_identity = false;
+    }
+
+    /// Applies a scaling to the current transform of the [`Frame`].
+    #[inline]
+    pub fn scale(&mut self, scale: f32) {
+        self.transforms.current.raw =
+            self.transforms.current.raw.pre_scale(scale, scale);
+
+        self.transforms.current.is_identity = false;
+    }
+
+    /// Produces the [`Geometry`] representing everything drawn on the [`Frame`].
+    pub fn into_geometry(mut self) -> Geometry {
+        self.flush_buffers();
+
+        Geometry::from_primitive(Primitive::Group {
+            primitives: self.primitives,
+        })
+    }
+}
+
+struct FillVertex([f32; 4]);
+
+impl tessellation::FillVertexConstructor<triangle::Vertex2D> for FillVertex {
+    fn new_vertex(
+        &mut self,
+        vertex: tessellation::FillVertex<'_>,
+    ) -> triangle::Vertex2D {
+        let position = vertex.position();
+
+        triangle::Vertex2D {
+            position: [position.x, position.y],
+            color: self.0,
+        }
+    }
+}
+
+struct StrokeVertex([f32; 4]);
+
+impl tessellation::StrokeVertexConstructor<triangle::Vertex2D> for StrokeVertex {
+    fn new_vertex(
+        &mut self,
+        vertex: tessellation::StrokeVertex<'_, '_>,
+    ) -> triangle::Vertex2D {
+        let position = vertex.position();
+
+        triangle::Vertex2D {
+            position: [position.x, position.y],
+            color: self.0,
+        }
+    }
+}
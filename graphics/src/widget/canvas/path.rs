@@ -0,0 +1,95 @@
+//! Build different kinds of 2D shapes.
+pub mod arc;
+
+mod builder;
+mod svg;
+
+pub use arc::Arc;
+pub use builder::Builder;
+
+use iced_native::{Point, Size};
+
+/// An immutable set of points that may or may not be connected.
+///
+/// A single [`Path`] can represent different kinds of 2D shapes!
+#[derive(Debug, Clone)]
+pub struct Path {
+    raw: lyon_path::Path,
+}
+
+impl Path {
+    /// Creates a new [`Path`] with the provided closure.
+    ///
+    /// Use the [`Builder`] to configure your [`Path`].
+    pub fn new(f: impl FnOnce(&mut Builder)) -> Self {
+        let mut builder = Builder::new();
+
+        // TODO: Make it pure instead of side-effect-based (?)
+        f(&mut builder);
+
+        builder.build()
+    }
+
+    /// Creates a new [`Path`] representing a line segment given its starting
+    /// and end points.
+    pub fn line(from: Point, to: Point) -> Self {
+        Self::new(|p| {
+            p.move_to(from);
+            p.line_to(to);
+        })
+    }
+
+    /// Creates a new [`Path`] representing a rectangle given its top-left
+    /// corner coordinate and its `Size`.
+    pub fn rectangle(top_left: Point, size: Size) -> Self {
+        Self::new(|p| p.rectangle(top_left, size))
+    }
+
+    /// Creates a new [`Path`] representing a circle given its center
+    /// coordinate and its radius.
+    pub fn circle(center: Point, radius: f32) -> Self {
+        Self::new(|p| p.circle(center, radius))
+    }
+
+    /// Creates a new [`Path`] from the given SVG path data string.
+    ///
+    /// The `d` attribute follows the standard [SVG path mini-language],
+    /// supporting the `M`/`L`/`H`/`V`/`C`/`S`/`Q`/`T`/`A`/`Z` commands (and
+    /// their lowercase, relative counterparts). This is handy for rendering
+    /// vector assets authored in design tools directly onto a [`Frame`]
+    /// without hand-coding every segment.
+    ///
+    /// If any part of the string cannot be parsed, the error is silently
+    /// discarded and an empty [`Path`] is returned. Use [`Path::parse_svg`] if you
+    /// need to surface the error instead.
+    ///
+    /// [SVG path mini-language]: https://www.w3.org/TR/SVG/paths.html#PathData
+    /// [`Frame`]: crate::canvas::Frame
+    pub fn from_svg(d: &str) -> Self {
+        Self::parse_svg(d).unwrap_or_else(|_| Self::new(|_| {}))
+    }
+
+    /// Attempts to create a new [`Path`] from the given SVG path data string,
+    /// returning an [`svg::Error`] if the data is malformed.
+    ///
+    /// See [`Path::from_svg`] for the list of supported commands.
+    pub fn parse_svg(d: &str) -> Result<Self, svg::Error> {
+        let mut builder = Builder::new();
+
+        svg::parse(d, &mut builder)?;
+
+        Ok(builder.build())
+    }
+
+    #[inline]
+    pub(crate) fn raw(&self) -> &lyon_path::Path {
+        &self.raw
+    }
+
+    #[inline]
+    pub(crate) fn transformed(&self, transform: &lyon_path::math::Transform) -> Path {
+        Path {
+            raw: self.raw.clone().transformed(transform),
+        }
+    }
+}
@@ -0,0 +1,639 @@
+//! Parse the standard SVG path mini-language into a [`Builder`].
+use super::Builder;
+
+use iced_native::Point;
+
+use std::f32::consts::PI;
+use std::fmt;
+
+/// An error produced while parsing SVG path data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A command letter was expected, but another character or an unknown
+    /// command letter was found.
+    UnexpectedToken(char),
+    /// A command was missing one or more of its numeric operands.
+    MissingOperand,
+    /// An operand could not be parsed as a number.
+    InvalidNumber(String),
+    /// A `moveto` command was expected before any drawing command.
+    MissingMoveTo,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedToken(c) => {
+                write!(f, "unexpected token in path data: {:?}", c)
+            }
+            Error::MissingOperand => {
+                write!(f, "a command is missing one of its operands")
+            }
+            Error::InvalidNumber(n) => {
+                write!(f, "could not parse number in path data: {:?}", n)
+            }
+            Error::MissingMoveTo => {
+                write!(f, "path data must begin with a `moveto` command")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses the SVG path data in `d`, driving the given [`Builder`].
+pub fn parse(d: &str, builder: &mut Builder) -> Result<(), Error> {
+    let mut parser = Parser::new(d);
+    let mut state = State::default();
+
+    while let Some(command) = parser.command()? {
+        state.run(command, &mut parser, builder)?;
+    }
+
+    Ok(())
+}
+
+/// The running state of a path walk.
+#[derive(Default)]
+struct State {
+    /// The current point.
+    current: Point,
+    /// The start of the current sub-path, used by `Z`.
+    start: Point,
+    /// The previous cubic control point, reflected by `S`.
+    last_cubic: Option<Point>,
+    /// The previous quadratic control point, reflected by `T`.
+    last_quadratic: Option<Point>,
+    /// Whether a `moveto` has been issued yet.
+    started: bool,
+}
+
+impl State {
+    fn run(
+        &mut self,
+        command: char,
+        parser: &mut Parser<'_>,
+        builder: &mut Builder,
+    ) -> Result<(), Error> {
+        let relative = command.is_ascii_lowercase();
+
+        match command.to_ascii_uppercase() {
+            'M' => {
+                let to = self.point(parser, relative)?;
+
+                builder.move_to(to);
+
+                self.current = to;
+                self.start = to;
+                self.started = true;
+                self.clear_controls();
+
+                // Any further coordinate pairs behave as implicit `lineto`.
+                while parser.has_operand() {
+                    let to = self.point(parser, relative)?;
+
+                    builder.line_to(to);
+                    self.current = to;
+                    self.clear_controls();
+                }
+            }
+            'L' => {
+                self.ensure_started()?;
+
+                loop {
+                    let to = self.point(parser, relative)?;
+
+                    builder.line_to(to);
+                    self.current = to;
+                    self.clear_controls();
+
+                    if !parser.has_operand() {
+                        break;
+                    }
+                }
+            }
+            'H' => {
+                self.ensure_started()?;
+
+                loop {
+                    let x = parser.number()?;
+                    let x = if relative { self.current.x + x } else { x };
+                    let to = Point::new(x, self.current.y);
+
+                    builder.line_to(to);
+                    self.current = to;
+                    self.clear_controls();
+
+                    if !parser.has_operand() {
+                        break;
+                    }
+                }
+            }
+            'V' => {
+                self.ensure_started()?;
+
+                loop {
+                    let y = parser.number()?;
+                    let y = if relative { self.current.y + y } else { y };
+                    let to = Point::new(self.current.x, y);
+
+                    builder.line_to(to);
+                    self.current = to;
+                    self.clear_controls();
+
+                    if !parser.has_operand() {
+                        break;
+                    }
+                }
+            }
+            'C' => {
+                self.ensure_started()?;
+
+                loop {
+                    let control_a = self.point(parser, relative)?;
+                    let control_b = self.point(parser, relative)?;
+                    let to = self.point(parser, relative)?;
+
+                    builder.bezier_curve_to(control_a, control_b, to);
+
+                    self.current = to;
+                    self.last_cubic = Some(control_b);
+                    self.last_quadratic = None;
+
+                    if !parser.has_operand() {
+                        break;
+                    }
+                }
+            }
+            'S' => {
+                self.ensure_started()?;
+
+                loop {
+                    let control_a = self.reflected_cubic();
+                    let control_b = self.point(parser, relative)?;
+                    let to = self.point(parser, relative)?;
+
+                    builder.bezier_curve_to(control_a, control_b, to);
+
+                    self.current = to;
+                    self.last_cubic = Some(control_b);
+                    self.last_quadratic = None;
+
+                    if !parser.has_operand() {
+                        break;
+                    }
+                }
+            }
+            'Q' => {
+                self.ensure_started()?;
+
+                loop {
+                    let control = self.point(parser, relative)?;
+                    let to = self.point(parser, relative)?;
+
+                    builder.quadratic_curve_to(control, to);
+
+                    self.current = to;
+                    self.last_quadratic = Some(control);
+                    self.last_cubic = None;
+
+                    if !parser.has_operand() {
+                        break;
+                    }
+                }
+            }
+            'T' => {
+                self.ensure_started()?;
+
+                loop {
+                    let control = self.reflected_quadratic();
+                    let to = self.point(parser, relative)?;
+
+                    builder.quadratic_curve_to(control, to);
+
+                    self.current = to;
+                    self.last_quadratic = Some(control);
+                    self.last_cubic = None;
+
+                    if !parser.has_operand() {
+                        break;
+                    }
+                }
+            }
+            'A' => {
+                self.ensure_started()?;
+
+                loop {
+                    let rx = parser.number()?.abs();
+                    let ry = parser.number()?.abs();
+                    let rotation = parser.number()?.to_radians();
+                    let large_arc = parser.flag()?;
+                    let sweep = parser.flag()?;
+                    let to = self.point(parser, relative)?;
+
+                    self.arc_to(builder, rx, ry, rotation, large_arc, sweep, to);
+
+                    self.current = to;
+                    self.clear_controls();
+
+                    if !parser.has_operand() {
+                        break;
+                    }
+                }
+            }
+            'Z' => {
+                builder.close();
+                self.current = self.start;
+                self.clear_controls();
+            }
+            other => return Err(Error::UnexpectedToken(other)),
+        }
+
+        Ok(())
+    }
+
+    fn ensure_started(&self) -> Result<(), Error> {
+        if self.started {
+            Ok(())
+        } else {
+            Err(Error::MissingMoveTo)
+        }
+    }
+
+    fn clear_controls(&mut self) {
+        self.last_cubic = None;
+        self.last_quadratic = None;
+    }
+
+    /// Reads a coordinate pair, resolving relative commands.
+    fn point(
+        &self,
+        parser: &mut Parser<'_>,
+        relative: bool,
+    ) -> Result<Point, Error> {
+        let x = parser.number()?;
+        let y = parser.number()?;
+
+        Ok(if relative {
+            Point::new(self.current.x + x, self.current.y + y)
+        } else {
+            Point::new(x, y)
+        })
+    }
+
+    /// The first control point of a smooth cubic: the reflection of the
+    /// previous cubic control point about the current point, or the current
+    /// point itself when the previous command was not a cubic.
+    fn reflected_cubic(&self) -> Point {
+        match self.last_cubic {
+            Some(control) => Point::new(
+                2.0 * self.current.x - control.x,
+                2.0 * self.current.y - control.y,
+            ),
+            None => self.current,
+        }
+    }
+
+    /// The reflected control point of a smooth quadratic.
+    fn reflected_quadratic(&self) -> Point {
+        match self.last_quadratic {
+            Some(control) => Point::new(
+                2.0 * self.current.x - control.x,
+                2.0 * self.current.y - control.y,
+            ),
+            None => self.current,
+        }
+    }
+
+    /// Emits an elliptical arc as a series of cubic Bézier segments.
+    #[allow(clippy::too_many_arguments)]
+    fn arc_to(
+        &self,
+        builder: &mut Builder,
+        mut rx: f32,
+        mut ry: f32,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Point,
+    ) {
+        let from = self.current;
+
+        // An arc with a zero radius (or no displacement) degenerates to a
+        // straight line, per the SVG specification.
+        if rx == 0.0 || ry == 0.0 || (from.x == to.x && from.y == to.y) {
+            builder.line_to(to);
+            return;
+        }
+
+        let (sin_phi, cos_phi) = rotation.sin_cos();
+
+        let dx = (from.x - to.x) / 2.0;
+        let dy = (from.y - to.y) / 2.0;
+
+        let x1 = cos_phi * dx + sin_phi * dy;
+        let y1 = -sin_phi * dx + cos_phi * dy;
+
+        // Scale up the radii if they are too small to span the endpoints.
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+
+        if lambda > 1.0 {
+            let scale = lambda.sqrt();
+            rx *= scale;
+            ry *= scale;
+        }
+
+        let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+
+        let numerator = (rx * rx * ry * ry
+            - rx * rx * y1 * y1
+            - ry * ry * x1 * x1)
+            .max(0.0);
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+
+        let coefficient = sign * (numerator / denominator).sqrt();
+
+        let cx1 = coefficient * rx * y1 / ry;
+        let cy1 = -coefficient * ry * x1 / rx;
+
+        let cx = cos_phi * cx1 - sin_phi * cy1 + (from.x + to.x) / 2.0;
+        let cy = sin_phi * cx1 + cos_phi * cy1 + (from.y + to.y) / 2.0;
+
+        let start_angle = angle(1.0, 0.0, (x1 - cx1) / rx, (y1 - cy1) / ry);
+
+        let mut sweep_angle = angle(
+            (x1 - cx1) / rx,
+            (y1 - cy1) / ry,
+            (-x1 - cx1) / rx,
+            (-y1 - cy1) / ry,
+        );
+
+        if !sweep && sweep_angle > 0.0 {
+            sweep_angle -= 2.0 * PI;
+        } else if sweep && sweep_angle < 0.0 {
+            sweep_angle += 2.0 * PI;
+        }
+
+        // Split the sweep into segments no wider than 90 degrees for accuracy.
+        let segments = (sweep_angle.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+        let delta = sweep_angle / segments as f32;
+        let alpha =
+            4.0 / 3.0 * (delta / 4.0).tan();
+
+        let mut angle = start_angle;
+
+        for _ in 0..segments {
+            let (sin_a, cos_a) = angle.sin_cos();
+            let (sin_b, cos_b) = (angle + delta).sin_cos();
+
+            let p1 = on_ellipse(cx, cy, rx, ry, cos_phi, sin_phi, cos_a, sin_a);
+            let p2 = on_ellipse(cx, cy, rx, ry, cos_phi, sin_phi, cos_b, sin_b);
+
+            let t1 = tangent(rx, ry, cos_phi, sin_phi, cos_a, sin_a);
+            let t2 = tangent(rx, ry, cos_phi, sin_phi, cos_b, sin_b);
+
+            let control_a =
+                Point::new(p1.x + alpha * t1.x, p1.y + alpha * t1.y);
+            let control_b =
+                Point::new(p2.x - alpha * t2.x, p2.y - alpha * t2.y);
+
+            builder.bezier_curve_to(control_a, control_b, p2);
+
+            angle += delta;
+        }
+    }
+}
+
+fn angle(ux: f32, uy: f32, vx: f32, vy: f32) -> f32 {
+    let dot = ux * vx + uy * vy;
+    let len = (ux * ux + uy * uy).sqrt() * (vx * vx + vy * vy).sqrt();
+    let sign = if ux * vy - uy * vx < 0.0 { -1.0 } else { 1.0 };
+
+    sign * (dot / len).clamp(-1.0, 1.0).acos()
+}
+
+fn on_ellipse(
+    cx: f32,
+    cy: f32,
+    rx: f32,
+    ry: f32,
+    cos_phi: f32,
+    sin_phi: f32,
+    cos_a: f32,
+    sin_a: f32,
+) -> Point {
+    let x = rx * cos_a;
+    let y = ry * sin_a;
+
+    Point::new(
+        cx + cos_phi * x - sin_phi * y,
+        cy + sin_phi * x + cos_phi * y,
+    )
+}
+
+fn tangent(
+    rx: f32,
+    ry: f32,
+    cos_phi: f32,
+    sin_phi: f32,
+    cos_a: f32,
+    sin_a: f32,
+) -> Point {
+    let x = -rx * sin_a;
+    let y = ry * cos_a;
+
+    Point::new(cos_phi * x - sin_phi * y, sin_phi * x + cos_phi * y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_ok(d: &str) {
+        let mut builder = Builder::new();
+        parse(d, &mut builder).expect("valid path data");
+    }
+
+    #[test]
+    fn accepts_all_commands() {
+        parse_ok(
+            "M10 10 L20 20 H30 V40 C50 50 60 60 70 70 \
+             S80 80 90 90 Q100 100 110 110 T120 120 Z",
+        );
+    }
+
+    #[test]
+    fn relative_commands_and_implicit_lineto() {
+        // A `moveto` followed by extra pairs continues as implicit `lineto`.
+        parse_ok("m0 0 10 10 l5 5 5 5");
+    }
+
+    #[test]
+    fn zero_radius_arc_degenerates_to_line() {
+        parse_ok("M0 0 A0 0 0 0 0 10 10");
+    }
+
+    #[test]
+    fn packed_arc_flags_parse() {
+        // The large-arc and sweep flags are single digits and may run straight
+        // into the following coordinate without a separator.
+        parse_ok("M0 0 A5 5 0 0110 10");
+    }
+
+    #[test]
+    fn missing_trailing_close_is_fillable() {
+        parse_ok("M0 0 L10 0 L10 10 L0 10");
+    }
+
+    #[test]
+    fn drawing_before_moveto_is_rejected() {
+        let mut builder = Builder::new();
+        assert_eq!(parse("L10 10", &mut builder), Err(Error::MissingMoveTo));
+    }
+
+    #[test]
+    fn unknown_command_is_rejected() {
+        let mut builder = Builder::new();
+        assert_eq!(
+            parse("M0 0 K5 5", &mut builder),
+            Err(Error::UnexpectedToken('K'))
+        );
+    }
+
+    #[test]
+    fn missing_operand_is_rejected() {
+        let mut builder = Builder::new();
+        assert_eq!(parse("M0", &mut builder), Err(Error::MissingOperand));
+    }
+}
+
+/// A tiny cursor over the path data string.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(d: &'a str) -> Self {
+        Parser {
+            bytes: d.as_bytes(),
+            offset: 0,
+        }
+    }
+
+    /// Skips any whitespace and a single optional comma separator.
+    fn skip_separators(&mut self) {
+        while self.offset < self.bytes.len() {
+            match self.bytes[self.offset] {
+                b' ' | b'\t' | b'\r' | b'\n' => self.offset += 1,
+                _ => break,
+            }
+        }
+
+        if self.offset < self.bytes.len() && self.bytes[self.offset] == b',' {
+            self.offset += 1;
+
+            while self.offset < self.bytes.len() {
+                match self.bytes[self.offset] {
+                    b' ' | b'\t' | b'\r' | b'\n' => self.offset += 1,
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    /// Reads the next command letter, if any.
+    fn command(&mut self) -> Result<Option<char>, Error> {
+        self.skip_separators();
+
+        match self.bytes.get(self.offset) {
+            None => Ok(None),
+            Some(&byte) => {
+                let c = byte as char;
+
+                if c.is_ascii_alphabetic() {
+                    self.offset += 1;
+                    Ok(Some(c))
+                } else {
+                    Err(Error::UnexpectedToken(c))
+                }
+            }
+        }
+    }
+
+    /// Returns whether the next token looks like a numeric operand, meaning
+    /// the current command should repeat.
+    fn has_operand(&mut self) -> bool {
+        self.skip_separators();
+
+        match self.bytes.get(self.offset) {
+            Some(&byte) => {
+                let c = byte as char;
+                c.is_ascii_digit() || c == '+' || c == '-' || c == '.'
+            }
+            None => false,
+        }
+    }
+
+    /// Reads a floating-point number, honoring signs and scientific notation.
+    fn number(&mut self) -> Result<f32, Error> {
+        self.skip_separators();
+
+        let start = self.offset;
+
+        if matches!(self.bytes.get(self.offset), Some(b'+') | Some(b'-')) {
+            self.offset += 1;
+        }
+
+        let mut seen_dot = false;
+
+        while let Some(&byte) = self.bytes.get(self.offset) {
+            match byte {
+                b'0'..=b'9' => self.offset += 1,
+                b'.' if !seen_dot => {
+                    seen_dot = true;
+                    self.offset += 1;
+                }
+                b'e' | b'E' => {
+                    self.offset += 1;
+
+                    if matches!(
+                        self.bytes.get(self.offset),
+                        Some(b'+') | Some(b'-')
+                    ) {
+                        self.offset += 1;
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        if self.offset == start {
+            return Err(Error::MissingOperand);
+        }
+
+        let raw = std::str::from_utf8(&self.bytes[start..self.offset])
+            .map_err(|_| Error::MissingOperand)?;
+
+        raw.parse::<f32>()
+            .map_err(|_| Error::InvalidNumber(raw.to_string()))
+    }
+
+    /// Reads an arc flag: a single `0` or `1` digit with optional surrounding
+    /// whitespace, as the flags are not separated from the following number.
+    fn flag(&mut self) -> Result<bool, Error> {
+        self.skip_separators();
+
+        match self.bytes.get(self.offset) {
+            Some(b'0') => {
+                self.offset += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.offset += 1;
+                Ok(true)
+            }
+            _ => Err(Error::MissingOperand),
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Rasterize canvas [`Geometry`] to an image, independent of the window.
+//!
+//! This runs the canvas draw pipeline into an offscreen BGRA buffer, so
+//! animations can be rendered at a fixed instant and hashed or diffed in a
+//! golden-image test, and generated vector art can be exported to PNG.
+//!
+//! The rasterizer is deterministic: the same [`Program`] and `size` always
+//! produce the same bytes, with triangles blended in a fixed order.
+use crate::canvas::{Cursor, Program};
+use crate::Primitive;
+
+use iced_native::{image, Point, Rectangle, Size};
+
+/// Renders the given [`Program`] into an offscreen image of the given `size`.
+///
+/// The cursor is reported as [`Cursor::Unavailable`], matching a headless
+/// render with no pointer.
+pub fn render<P>(program: &P, size: Size) -> image::Handle
+where
+    P: Program,
+{
+    let bounds = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: size.width,
+        height: size.height,
+    };
+
+    let pixels = render_to_pixels(program, bounds);
+
+    image::Handle::from_pixels(
+        size.width as u32,
+        size.height as u32,
+        pixels,
+    )
+}
+
+/// Renders the [`Program`] and returns the raw, row-major BGRA8 pixels,
+/// re-encoded to sRGB and ready for [`image::Handle::from_pixels`].
+///
+/// This is the deterministic building block behind [`render`]; golden-image
+/// tests can hash the returned buffer directly.
+pub fn render_to_pixels<P>(program: &P, bounds: Rectangle) -> Vec<u8>
+where
+    P: Program,
+{
+    let width = bounds.width.max(0.0) as usize;
+    let height = bounds.height.max(0.0) as usize;
+
+    let mut target = Target {
+        width,
+        height,
+        pixels: vec![0; width * height * 4],
+    };
+
+    for geometry in program.draw(bounds, Cursor::Unavailable) {
+        rasterize(&geometry.into_primitive(), 0.0, 0.0, &mut target);
+    }
+
+    target.pixels
+}
+
+/// The offscreen buffer a [`Primitive`] is painted onto.
+struct Target {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Target {
+    fn blend(&mut self, x: usize, y: usize, color: [f32; 4]) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let alpha = color[3].clamp(0.0, 1.0);
+        let offset = (y * self.width + x) * 4;
+
+        // Vertex colors are stored linearized, so re-encode to sRGB before
+        // packing. iced's `image::Handle::from_pixels` expects BGRA, so the
+        // color channels are written in reverse order.
+        for channel in 0..3 {
+            let source =
+                linear_to_srgb(color[channel].clamp(0.0, 1.0)) * 255.0;
+            let destination = self.pixels[offset + (2 - channel)] as f32;
+
+            self.pixels[offset + (2 - channel)] =
+                (source * alpha + destination * (1.0 - alpha)).round() as u8;
+        }
+
+        let destination_alpha = self.pixels[offset + 3] as f32 / 255.0;
+        let blended = alpha + destination_alpha * (1.0 - alpha);
+
+        self.pixels[offset + 3] = (blended * 255.0) as u8;
+    }
+}
+
+/// Encodes a linear color component into sRGB space.
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::canvas::shape::{Rectangle as RectangleShape, Shape};
+
+    use iced_native::Color;
+
+    fn red_square() -> Vec<Box<dyn Shape>> {
+        vec![Box::new(RectangleShape {
+            top_left: Point::ORIGIN,
+            size: Size::new(4.0, 4.0),
+            fill: Some(Color::from_rgb(1.0, 0.0, 0.0).into()),
+            stroke: None,
+        })]
+    }
+
+    #[test]
+    fn render_is_deterministic() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 4.0,
+            height: 4.0,
+        };
+
+        let first = render_to_pixels(&red_square(), bounds);
+        let second = render_to_pixels(&red_square(), bounds);
+
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 4 * 4 * 4);
+    }
+
+    #[test]
+    fn opaque_red_packs_as_srgb_bgra() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 4.0,
+            height: 4.0,
+        };
+
+        let pixels = render_to_pixels(&red_square(), bounds);
+
+        // The center pixel is fully covered; channels are BGRA and opaque red
+        // (linear 1.0) re-encodes to a full-intensity byte.
+        let offset = (4 + 1) * 4;
+
+        assert_eq!(pixels[offset], 0); // blue
+        assert_eq!(pixels[offset + 1], 0); // green
+        assert_eq!(pixels[offset + 2], 255); // red
+        assert_eq!(pixels[offset + 3], 255); // alpha
+    }
+}
+
+fn rasterize(primitive: &Primitive, dx: f32, dy: f32, target: &mut Target) {
+    match primitive {
+        Primitive::Group { primitives } => {
+            for primitive in primitives {
+                rasterize(primitive, dx, dy, target);
+            }
+        }
+        Primitive::Translate {
+            translation,
+            content,
+        } => {
+            rasterize(content, dx + translation.x, dy + translation.y, target);
+        }
+        Primitive::Mesh2D { buffers, .. } => {
+            for indices in buffers.indices.chunks_exact(3) {
+                let a = &buffers.vertices[indices[0] as usize];
+                let b = &buffers.vertices[indices[1] as usize];
+                let c = &buffers.vertices[indices[2] as usize];
+
+                fill_triangle(
+                    [
+                        Point::new(a.position[0] + dx, a.position[1] + dy),
+                        Point::new(b.position[0] + dx, b.position[1] + dy),
+                        Point::new(c.position[0] + dx, c.position[1] + dy),
+                    ],
+                    [a.color, b.color, c.color],
+                    target,
+                );
+            }
+        }
+        // Text and raster images require the full text/image pipelines and are
+        // not rasterized by this deterministic software path.
+        _ => {}
+    }
+}
+
+/// Fills a triangle using barycentric coordinates, interpolating the per-vertex
+/// colors at each covered pixel center.
+fn fill_triangle(
+    vertices: [Point; 3],
+    colors: [[f32; 4]; 3],
+    target: &mut Target,
+) {
+    let min_x = vertices.iter().map(|p| p.x).fold(f32::INFINITY, f32::min);
+    let max_x = vertices
+        .iter()
+        .map(|p| p.x)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let min_y = vertices.iter().map(|p| p.y).fold(f32::INFINITY, f32::min);
+    let max_y = vertices
+        .iter()
+        .map(|p| p.y)
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    let area = edge(vertices[0], vertices[1], vertices[2]);
+
+    if area == 0.0 {
+        return;
+    }
+
+    let min_x = min_x.floor().max(0.0) as usize;
+    let min_y = min_y.floor().max(0.0) as usize;
+    let max_x = (max_x.ceil() as usize).min(target.width);
+    let max_y = (max_y.ceil() as usize).min(target.height);
+
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let point = Point::new(x as f32 + 0.5, y as f32 + 0.5);
+
+            let w0 = edge(vertices[1], vertices[2], point) / area;
+            let w1 = edge(vertices[2], vertices[0], point) / area;
+            let w2 = edge(vertices[0], vertices[1], point) / area;
+
+            if w0 >= 0.0 && w1 >= 0.0 && w2 >= 0.0 {
+                let mut color = [0.0; 4];
+
+                for channel in 0..4 {
+                    color[channel] = w0 * colors[0][channel]
+                        + w1 * colors[1][channel]
+                        + w2 * colors[2][channel];
+                }
+
+                target.blend(x, y, color);
+            }
+        }
+    }
+}
+
+/// The signed area of the parallelogram spanned by `ab` and `ac`, i.e. twice
+/// the signed area of the triangle `abc`.
+fn edge(a: Point, b: Point, c: Point) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
@@ -0,0 +1,171 @@
+//! Paint reusable 2D shapes onto a [`Frame`].
+//!
+//! Instead of re-deriving geometry every frame with imperative `move_to`,
+//! `line_to` and `arc_to` calls, a [`Shape`] knows how to paint itself. A
+//! handful of batteries-included shapes —a [`Line`], a [`Rectangle`], a set of
+//! [`Points`] and a [`Grid`]— are provided so the [`Canvas`] can be used as a
+//! composable chart and plotting surface.
+//!
+//! [`Canvas`]: crate::canvas::Canvas
+use crate::canvas::{Fill, Frame, Geometry, Path, Program, Stroke};
+
+use iced_native::{Color, Point, Size};
+
+/// A 2D shape that knows how to paint itself onto a [`Frame`].
+pub trait Shape {
+    /// Draws the [`Shape`] onto the given [`Frame`].
+    fn draw(&self, frame: &mut Frame);
+}
+
+impl Frame {
+    /// Draws the given [`Shape`] onto the [`Frame`].
+    pub fn draw_shape(&mut self, shape: &impl Shape) {
+        shape.draw(self);
+    }
+}
+
+/// A straight line between two points.
+#[derive(Debug, Clone, Copy)]
+pub struct Line {
+    /// The starting point of the [`Line`].
+    pub from: Point,
+    /// The end point of the [`Line`].
+    pub to: Point,
+    /// The [`Stroke`] used to paint the [`Line`].
+    pub stroke: Stroke,
+}
+
+impl Shape for Line {
+    fn draw(&self, frame: &mut Frame) {
+        frame.stroke(&Path::line(self.from, self.to), self.stroke);
+    }
+}
+
+/// An axis-aligned rectangle.
+///
+/// It is filled when [`fill`] is set and outlined when [`stroke`] is set; both
+/// may be combined.
+///
+/// [`fill`]: Self::fill
+/// [`stroke`]: Self::stroke
+#[derive(Debug, Clone, Copy)]
+pub struct Rectangle {
+    /// The top-left corner of the [`Rectangle`].
+    pub top_left: Point,
+    /// The size of the [`Rectangle`].
+    pub size: Size,
+    /// The [`Fill`] of the [`Rectangle`], if any.
+    pub fill: Option<Fill>,
+    /// The [`Stroke`] of the [`Rectangle`], if any.
+    pub stroke: Option<Stroke>,
+}
+
+impl Shape for Rectangle {
+    fn draw(&self, frame: &mut Frame) {
+        let path = Path::rectangle(self.top_left, self.size);
+
+        if let Some(fill) = self.fill {
+            frame.fill(&path, fill);
+        }
+
+        if let Some(stroke) = self.stroke {
+            frame.stroke(&path, stroke);
+        }
+    }
+}
+
+/// A scatter of filled circular points of a common radius and color.
+#[derive(Debug, Clone)]
+pub struct Points {
+    /// The center of each point.
+    pub coords: Vec<Point>,
+    /// The radius of every point.
+    pub radius: f32,
+    /// The color used to fill the points.
+    pub color: Color,
+}
+
+impl Shape for Points {
+    fn draw(&self, frame: &mut Frame) {
+        let points = Path::new(|builder| {
+            for coord in &self.coords {
+                builder.circle(*coord, self.radius);
+            }
+        });
+
+        frame.fill(&points, self.color);
+    }
+}
+
+/// A regular grid of evenly spaced lines, handy as an axis or plot backdrop.
+#[derive(Debug, Clone, Copy)]
+pub struct Grid {
+    /// The top-left corner of the [`Grid`].
+    pub top_left: Point,
+    /// The size of the [`Grid`].
+    pub size: Size,
+    /// The spacing between consecutive vertical and horizontal lines.
+    pub spacing: Size,
+    /// The [`Stroke`] used to paint the grid lines.
+    pub stroke: Stroke,
+}
+
+impl Shape for Grid {
+    fn draw(&self, frame: &mut Frame) {
+        if self.spacing.width <= 0.0 || self.spacing.height <= 0.0 {
+            return;
+        }
+
+        let lines = Path::new(|builder| {
+            let mut x = self.top_left.x;
+
+            while x <= self.top_left.x + self.size.width {
+                builder.move_to(Point::new(x, self.top_left.y));
+                builder.line_to(Point::new(
+                    x,
+                    self.top_left.y + self.size.height,
+                ));
+
+                x += self.spacing.width;
+            }
+
+            let mut y = self.top_left.y;
+
+            while y <= self.top_left.y + self.size.height {
+                builder.move_to(Point::new(self.top_left.x, y));
+                builder.line_to(Point::new(
+                    self.top_left.x + self.size.width,
+                    y,
+                ));
+
+                y += self.spacing.height;
+            }
+        });
+
+        frame.stroke(&lines, self.stroke);
+    }
+}
+
+/// A convenience [`Program`] that paints a list of [`Shape`]s in order.
+///
+/// This turns a `Vec<Box<dyn Shape>>` into a ready-to-use [`Canvas`] program,
+/// so a static scene does not need a bespoke `Program` implementation.
+///
+/// [`Canvas`]: crate::canvas::Canvas
+impl Program for Vec<Box<dyn Shape>> {
+    type Message = ();
+
+    fn draw(
+        &self,
+        bounds: iced_native::Rectangle,
+        _cursor: crate::canvas::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+
+        for shape in self {
+            shape.draw(&mut frame);
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
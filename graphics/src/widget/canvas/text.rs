@@ -0,0 +1,150 @@
+//! Lay out and draw text on a [`Frame`].
+//!
+//! [`Frame`]: crate::canvas::Frame
+use iced_native::{alignment, Color, Font, Point, Size};
+
+/// A bunch of text that can be drawn onto a [`Frame`].
+///
+/// Beyond a single line, a [`Text`] can break on `\n`, wrap inside an optional
+/// [`max_width`] box, and align itself both horizontally and vertically, so
+/// chart labels and annotations lay out correctly.
+///
+/// [`Frame`]: crate::canvas::Frame
+/// [`max_width`]: Self::max_width
+#[derive(Debug, Clone)]
+pub struct Text {
+    /// The contents of the text.
+    pub content: String,
+    /// The position of the text relative to the alignment properties.
+    pub position: Point,
+    /// The color of the text.
+    pub color: Color,
+    /// The size of the text.
+    pub size: f32,
+    /// The distance between the baselines of consecutive lines.
+    ///
+    /// A non-positive value falls back to `1.2 * size`.
+    pub line_height: f32,
+    /// The maximum width the text may occupy before wrapping, if any.
+    pub max_width: Option<f32>,
+    /// The font of the text.
+    pub font: Font,
+    /// The horizontal alignment of the text within its `max_width` box.
+    ///
+    /// Only `Left`, `Center` and `Right` are available: `alignment::Horizontal`
+    /// has no `Justify` variant, so justified layout is not supported.
+    pub horizontal_alignment: alignment::Horizontal,
+    /// The vertical alignment of the text.
+    pub vertical_alignment: alignment::Vertical,
+}
+
+impl Text {
+    /// The resolved distance between consecutive baselines.
+    pub(crate) fn resolved_line_height(&self) -> f32 {
+        if self.line_height > 0.0 {
+            self.line_height
+        } else {
+            self.size * 1.2
+        }
+    }
+
+    /// Breaks [`content`] into laid-out lines, honoring `\n` and the optional
+    /// [`max_width`] wrapping box.
+    ///
+    /// [`content`]: Self::content
+    /// [`max_width`]: Self::max_width
+    pub(crate) fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for paragraph in self.content.split('\n') {
+            match self.max_width {
+                Some(max_width) => self.wrap(paragraph, max_width, &mut lines),
+                None => lines.push(paragraph.to_string()),
+            }
+        }
+
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+
+        lines
+    }
+
+    fn wrap(&self, paragraph: &str, max_width: f32, lines: &mut Vec<String>) {
+        let mut current = String::new();
+
+        for word in paragraph.split(' ') {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", current, word)
+            };
+
+            if !current.is_empty()
+                && self.approximate_width(&candidate) > max_width
+            {
+                lines.push(std::mem::take(&mut current));
+                current = word.to_string();
+            } else {
+                current = candidate;
+            }
+        }
+
+        lines.push(current);
+    }
+
+    /// An approximation of the width of the given run, used to decide where
+    /// to break lines and to measure the laid-out lines in [`bounds`]. The
+    /// backend performs the exact shaping when the laid-out runs are painted.
+    ///
+    /// [`bounds`]: Self::bounds
+    pub(crate) fn approximate_width(&self, content: &str) -> f32 {
+        content.chars().count() as f32 * self.size * 0.5
+    }
+
+    /// The size of the bounding box of the given laid-out `lines`.
+    ///
+    /// The width is the widest laid-out line, not the `max_width` box, so that
+    /// [`Frame::measure_text`] reports the space the text actually occupies.
+    ///
+    /// [`Frame::measure_text`]: crate::canvas::Frame::measure_text
+    pub(crate) fn bounds(&self, lines: &[String]) -> Size {
+        let width = lines
+            .iter()
+            .map(|line| self.approximate_width(line))
+            .fold(0.0, f32::max);
+
+        Size::new(width, lines.len() as f32 * self.resolved_line_height())
+    }
+}
+
+impl Default for Text {
+    fn default() -> Text {
+        Text {
+            content: String::new(),
+            position: Point::ORIGIN,
+            color: Color::BLACK,
+            size: 16.0,
+            line_height: 0.0,
+            max_width: None,
+            font: Font::Default,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+        }
+    }
+}
+
+impl From<String> for Text {
+    fn from(content: String) -> Text {
+        Text {
+            content,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for Text {
+    fn from(content: &str) -> Text {
+        String::from(content).into()
+    }
+}
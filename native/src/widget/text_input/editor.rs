@@ -1,13 +1,37 @@
 use crate::text_input::{Cursor, Value};
 
+use unicode_segmentation::UnicodeSegmentation;
+
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    history: Option<&'a mut History>,
 }
 
 impl<'a> Editor<'a> {
     pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
+        Editor {
+            value,
+            cursor,
+            history: None,
+        }
+    }
+
+    /// Creates an [`Editor`] that records edit boundaries onto the given
+    /// [`History`], enabling [`undo`] and [`redo`].
+    ///
+    /// [`undo`]: Self::undo
+    /// [`redo`]: Self::redo
+    pub fn with_history(
+        value: &'a mut Value,
+        cursor: &'a mut Cursor,
+        history: &'a mut History,
+    ) -> Editor<'a> {
+        Editor {
+            value,
+            cursor,
+            history: Some(history),
+        }
     }
 
     pub fn contents(&self) -> String {
@@ -15,6 +39,14 @@ impl<'a> Editor<'a> {
     }
 
     pub fn insert(&mut self, character: char) {
+        // Replacing a non-empty selection is a destructive edit that must not
+        // coalesce with the typing before it, so it starts a new undo group.
+        if self.cursor.selection().is_some() {
+            self.end_group();
+        }
+
+        self.remember(Edit::Insert);
+
         match self.cursor.selection() {
             Some((left, right)) => {
                 self.value.remove_many(left, right);
@@ -28,6 +60,8 @@ impl<'a> Editor<'a> {
     }
 
     pub fn paste(&mut self, content: Value) {
+        self.remember(Edit::Other);
+
         let length = content.len();
 
         match self.cursor.selection() {
@@ -45,6 +79,8 @@ impl<'a> Editor<'a> {
     }
 
     pub fn backspace(&mut self) {
+        self.remember(Edit::Other);
+
         match self.cursor.selection() {
             Some((start, end)) => {
                 self.value.remove_many(start, end);
@@ -63,6 +99,8 @@ impl<'a> Editor<'a> {
     }
 
     pub fn delete(&mut self) {
+        self.remember(Edit::Other);
+
         match self.cursor.selection() {
             Some((start, end)) => {
                 self.value.remove_many(start, end);
@@ -77,4 +115,247 @@ impl<'a> Editor<'a> {
             }
         }
     }
+
+    pub fn delete_word_backward(&mut self) {
+        match self.cursor.selection() {
+            Some((start, end)) => {
+                self.remember(Edit::Other);
+                self.value.remove_many(start, end);
+                self.cursor.move_left(&self.value);
+            }
+            None => {
+                let end = self.cursor.start(&self.value);
+                let start = self.previous_word_boundary(end);
+
+                if start < end {
+                    self.remember(Edit::Other);
+                    self.value.remove_many(start, end);
+                    self.cursor.move_to(start);
+                }
+            }
+        }
+    }
+
+    pub fn delete_word_forward(&mut self) {
+        match self.cursor.selection() {
+            Some((start, end)) => {
+                self.remember(Edit::Other);
+                self.value.remove_many(start, end);
+                self.cursor.move_left(&self.value);
+            }
+            None => {
+                let start = self.cursor.end(&self.value);
+                let end = self.next_word_boundary(start);
+
+                if start < end {
+                    self.remember(Edit::Other);
+                    self.value.remove_many(start, end);
+                    self.cursor.move_to(start);
+                }
+            }
+        }
+    }
+
+    pub fn move_word_left(&mut self) {
+        let position =
+            self.previous_word_boundary(self.cursor.start(&self.value));
+
+        self.cursor.move_to(position);
+        self.end_group();
+    }
+
+    pub fn move_word_right(&mut self) {
+        let position = self.next_word_boundary(self.cursor.end(&self.value));
+
+        self.cursor.move_to(position);
+        self.end_group();
+    }
+
+    pub fn move_to_line_start(&mut self) {
+        self.cursor.move_to(0);
+        self.end_group();
+    }
+
+    pub fn move_to_line_end(&mut self) {
+        self.cursor.move_to(self.value.len());
+        self.end_group();
+    }
+
+    /// Restores the contents and cursor to the previous edit boundary, if any.
+    pub fn undo(&mut self) {
+        let current = self.snapshot();
+
+        let snapshot = self.history.as_mut().and_then(|history| {
+            history.last_edit = None;
+
+            history.undo.pop().map(|snapshot| {
+                history.redo.push(current);
+                snapshot
+            })
+        });
+
+        if let Some(snapshot) = snapshot {
+            self.restore(snapshot);
+        }
+    }
+
+    /// Reapplies the edit that was most recently undone, if any.
+    pub fn redo(&mut self) {
+        let current = self.snapshot();
+
+        let snapshot = self.history.as_mut().and_then(|history| {
+            history.last_edit = None;
+
+            history.redo.pop().map(|snapshot| {
+                history.undo.push(current);
+                snapshot
+            })
+        });
+
+        if let Some(snapshot) = snapshot {
+            self.restore(snapshot);
+        }
+    }
+
+    /// Records the current state onto the undo stack before an edit.
+    ///
+    /// Consecutive single-character inserts are coalesced into one group, so
+    /// typing a word undoes as a unit. Does nothing when the [`Editor`] was
+    /// created without a [`History`].
+    fn remember(&mut self, edit: Edit) {
+        let snapshot = self.snapshot();
+
+        if let Some(history) = self.history.as_mut() {
+            history.remember(snapshot, edit);
+        }
+    }
+
+    /// Closes the current coalescing group, so the next insert starts a new
+    /// undo entry.
+    fn end_group(&mut self) {
+        if let Some(history) = self.history.as_mut() {
+            history.end_group();
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            value: self.value.clone(),
+            cursor: *self.cursor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        *self.value = snapshot.value;
+        *self.cursor = snapshot.cursor;
+    }
+
+    /// Returns the grapheme index of the word boundary at or before `index`.
+    fn previous_word_boundary(&self, index: usize) -> usize {
+        let graphemes = self.graphemes();
+
+        if index == 0 {
+            return 0;
+        }
+
+        let mut cursor = index.min(graphemes.len());
+        let class = classify(&graphemes[cursor - 1]);
+
+        while cursor > 0 && classify(&graphemes[cursor - 1]) == class {
+            cursor -= 1;
+        }
+
+        cursor
+    }
+
+    /// Returns the grapheme index of the word boundary at or after `index`.
+    fn next_word_boundary(&self, index: usize) -> usize {
+        let graphemes = self.graphemes();
+
+        if index >= graphemes.len() {
+            return graphemes.len();
+        }
+
+        let mut cursor = index;
+        let class = classify(&graphemes[cursor]);
+
+        while cursor < graphemes.len() && classify(&graphemes[cursor]) == class {
+            cursor += 1;
+        }
+
+        cursor
+    }
+
+    fn graphemes(&self) -> Vec<String> {
+        self.value
+            .to_string()
+            .graphemes(true)
+            .map(String::from)
+            .collect()
+    }
+}
+
+/// The class of a grapheme for the purposes of word navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Whitespace,
+    Alphanumeric,
+    Punctuation,
+}
+
+fn classify(grapheme: &str) -> Class {
+    match grapheme.chars().next() {
+        Some(character) if character.is_whitespace() => Class::Whitespace,
+        Some(character) if character.is_alphanumeric() => Class::Alphanumeric,
+        _ => Class::Punctuation,
+    }
+}
+
+/// A bounded undo/redo history for an [`Editor`].
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<Edit>,
+}
+
+impl History {
+    /// The maximum number of undo groups kept around.
+    const MAX_GROUPS: usize = 100;
+
+    /// Pushes `snapshot` as a new undo boundary, coalescing consecutive
+    /// single-character inserts into one group.
+    fn remember(&mut self, snapshot: Snapshot, edit: Edit) {
+        let coalesce =
+            edit == Edit::Insert && self.last_edit == Some(Edit::Insert);
+
+        if !coalesce {
+            self.undo.push(snapshot);
+
+            if self.undo.len() > Self::MAX_GROUPS {
+                let _ = self.undo.remove(0);
+            }
+        }
+
+        self.redo.clear();
+        self.last_edit = Some(edit);
+    }
+
+    /// Closes the current coalescing group, so the next insert starts a new
+    /// undo entry.
+    fn end_group(&mut self) {
+        self.last_edit = None;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: Value,
+    cursor: Cursor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Insert,
+    Other,
 }